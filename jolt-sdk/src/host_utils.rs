@@ -1,8 +1,10 @@
 use std::fs::File;
+use std::io::Read;
 use std::path::PathBuf;
 
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-use eyre::Result;
+use eyre::{eyre, Result};
+use serde::{Deserialize, Serialize};
 
 pub use ark_bn254::{Fr as F, G1Projective as G};
 pub use ark_ec::CurveGroup;
@@ -23,6 +25,30 @@ pub use jolt_core::jolt::vm::{
 };
 pub use tracer;
 
+/// Magic bytes identifying a file as a framed Jolt proof container.
+const MAGIC: &[u8; 4] = b"JLTP";
+/// Version of the container framing (magic + version + metadata length prefix).
+/// Bumped whenever that framing, not the inner proof payload, changes shape.
+const FORMAT_VERSION: u16 = 1;
+/// Upper bound on the declared metadata length, so a truncated or maliciously
+/// crafted container can't force an allocation far larger than any real
+/// `ProofMetadata` could serialize to before we even try to read it.
+const MAX_METADATA_LEN: usize = 1 << 20;
+
+/// Metadata embedded in a proof container so a stale or mismatched proof can
+/// be rejected before cryptographic verification is attempted.
+#[derive(Serialize, Deserialize)]
+pub struct ProofMetadata {
+    /// SHA-256 of the guest ELF/bytecode this proof was generated against, hex-encoded.
+    pub elf_hash: String,
+    /// Human-readable description of the proof-system parameters used (preprocessing sizes, etc).
+    pub params: String,
+    /// Serialized public inputs the proof commits to.
+    pub public_inputs: Vec<u8>,
+    /// Serialized public outputs the proof commits to.
+    pub public_outputs: Vec<u8>,
+}
+
 #[derive(CanonicalSerialize, CanonicalDeserialize)]
 pub struct Proof {
     pub proof: RV32IJoltProof<F, G>,
@@ -37,17 +63,35 @@ impl Proof {
         Ok(buffer.len())
     }
 
-    /// Saves the proof to a file
-    pub fn save_to_file<P: Into<PathBuf>>(&self, path: P) -> Result<()> {
-        let file = File::create(path.into())?;
+    /// Saves the proof to a file, framed with `metadata` behind a magic
+    /// number and format version so a reader can validate the container
+    /// before touching the cryptographic payload.
+    pub fn save_to_file<P: Into<PathBuf>>(&self, path: P, metadata: &ProofMetadata) -> Result<()> {
+        let mut file = File::create(path.into())?;
+        write_header(&mut file, metadata)?;
         self.serialize_compressed(file)?;
         Ok(())
     }
 
-    /// Reads a proof from a file
-    pub fn from_file<P: Into<PathBuf>>(path: P) -> Result<Self> {
-        let file = File::open(path.into())?;
-        Ok(Proof::deserialize_compressed(file)?)
+    /// Reads a proof from a file, rejecting it if the container's format
+    /// version or embedded guest ELF hash doesn't match what's expected.
+    pub fn from_file<P: Into<PathBuf>>(path: P, expected_elf_hash: &str) -> Result<Self> {
+        let (metadata, mut file) = read_header(path.into())?;
+        if metadata.elf_hash != expected_elf_hash {
+            return Err(eyre!(
+                "proof was generated for guest ELF {}, expected {}",
+                metadata.elf_hash,
+                expected_elf_hash
+            ));
+        }
+        Ok(Proof::deserialize_compressed(&mut file)?)
+    }
+
+    /// Reads a proof container's metadata without validating it against an
+    /// expected guest ELF hash or attempting to deserialize the proof payload.
+    pub fn read_metadata<P: Into<PathBuf>>(path: P) -> Result<ProofMetadata> {
+        let (metadata, _file) = read_header(path.into())?;
+        Ok(metadata)
     }
 
     pub fn serialize_to_string(&self) -> Result<String> {
@@ -56,3 +100,50 @@ impl Proof {
         Ok(base64::encode(&buffer))
     }
 }
+
+fn write_header(file: &mut File, metadata: &ProofMetadata) -> Result<()> {
+    use std::io::Write;
+
+    file.write_all(MAGIC)?;
+    file.write_all(&FORMAT_VERSION.to_le_bytes())?;
+
+    let metadata_bytes = serde_json::to_vec(metadata)?;
+    file.write_all(&(metadata_bytes.len() as u32).to_le_bytes())?;
+    file.write_all(&metadata_bytes)?;
+
+    Ok(())
+}
+
+fn read_header(path: PathBuf) -> Result<(ProofMetadata, File)> {
+    let mut file = File::open(path)?;
+
+    let mut magic = [0u8; 4];
+    file.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(eyre!("not a Jolt proof file"));
+    }
+
+    let mut version_bytes = [0u8; 2];
+    file.read_exact(&mut version_bytes)?;
+    let version = u16::from_le_bytes(version_bytes);
+    if version != FORMAT_VERSION {
+        return Err(eyre!(
+            "unsupported proof container version {version}, expected {FORMAT_VERSION}"
+        ));
+    }
+
+    let mut len_bytes = [0u8; 4];
+    file.read_exact(&mut len_bytes)?;
+    let metadata_len = u32::from_le_bytes(len_bytes) as usize;
+    if metadata_len > MAX_METADATA_LEN {
+        return Err(eyre!(
+            "proof container claims {metadata_len}-byte metadata, exceeding the {MAX_METADATA_LEN}-byte limit"
+        ));
+    }
+
+    let mut metadata_bytes = vec![0u8; metadata_len];
+    file.read_exact(&mut metadata_bytes)?;
+    let metadata: ProofMetadata = serde_json::from_slice(&metadata_bytes)?;
+
+    Ok((metadata, file))
+}
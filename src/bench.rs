@@ -0,0 +1,222 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command as ProcessCommand,
+    time::Instant,
+};
+
+use eyre::{eyre, Result};
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sysinfo::System;
+
+use jolt_core::host;
+use jolt_core::jolt::vm::{rv32i_vm::RV32IJoltVM, Jolt};
+use jolt_sdk::Proof;
+
+/// A single named set of inputs to run a workload's entry function with.
+#[derive(Deserialize)]
+struct NamedInput {
+    name: String,
+    args: Vec<Value>,
+}
+
+/// A JSON workload file describing a guest program to prove and verify.
+#[derive(Deserialize)]
+struct Workload {
+    /// Human-readable name for this workload, used in the report.
+    name: String,
+    /// Path to the guest crate to build, relative to the workload file.
+    guest: PathBuf,
+    /// `#[jolt::provable]` entry function to invoke.
+    function: String,
+    /// Named input vectors to run the entry function with.
+    inputs: Vec<NamedInput>,
+    #[serde(default = "default_warmup_runs")]
+    warmup_runs: usize,
+    #[serde(default = "default_measured_runs")]
+    measured_runs: usize,
+}
+
+fn default_warmup_runs() -> usize {
+    1
+}
+
+fn default_measured_runs() -> usize {
+    5
+}
+
+#[derive(Serialize)]
+struct RunMetrics {
+    input: String,
+    prover_time_ms: f64,
+    verifier_time_ms: f64,
+    proof_size_bytes: usize,
+}
+
+#[derive(Serialize)]
+struct WorkloadReport {
+    name: String,
+    function: String,
+    runs: Vec<RunMetrics>,
+}
+
+#[derive(Serialize)]
+struct HostInfo {
+    os: String,
+    os_version: String,
+    host_name: String,
+    cpus: usize,
+    ram_gb: f64,
+}
+
+#[derive(Serialize)]
+struct BenchReport {
+    build_tag: String,
+    host: HostInfo,
+    workloads: Vec<WorkloadReport>,
+}
+
+pub fn run(workload_paths: Vec<PathBuf>, report_url: Option<String>) {
+    let mut workloads = Vec::with_capacity(workload_paths.len());
+    for path in &workload_paths {
+        match bench_workload(path) {
+            Ok(report) => workloads.push(report),
+            Err(err) => eprintln!("skipping {}: {err}", path.display()),
+        }
+    }
+
+    let report = BenchReport {
+        build_tag: git_describe(),
+        host: gather_host_info(),
+        workloads,
+    };
+
+    print_report(&report);
+
+    if let Some(url) = report_url {
+        if let Err(err) = submit_report(&url, &report) {
+            eprintln!("failed to submit report to {url}: {err}");
+        }
+    }
+}
+
+fn bench_workload(path: &Path) -> Result<WorkloadReport> {
+    let contents = fs::read_to_string(path)?;
+    let workload: Workload = serde_json::from_str(&contents)?;
+
+    let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let guest_dir = base_dir.join(&workload.guest);
+
+    let mut program = host::Program::new(
+        guest_dir
+            .to_str()
+            .ok_or_else(|| eyre!("non-utf8 guest path"))?,
+    );
+    program.set_func(&workload.function);
+
+    for input in &workload.inputs {
+        for _ in 0..workload.warmup_runs {
+            run_once(&mut program, &input.args)?;
+        }
+    }
+
+    let mut runs = Vec::with_capacity(workload.inputs.len() * workload.measured_runs);
+    for input in &workload.inputs {
+        for _ in 0..workload.measured_runs {
+            runs.push(run_once(&mut program, &input.args).map(|metrics| RunMetrics {
+                input: input.name.clone(),
+                ..metrics
+            })?);
+        }
+    }
+
+    Ok(WorkloadReport {
+        name: workload.name,
+        function: workload.function,
+        runs,
+    })
+}
+
+fn run_once(program: &mut host::Program, args: &[Value]) -> Result<RunMetrics> {
+    let (io_device, bytecode, trace) = program.trace(args);
+    let preprocessing = RV32IJoltVM::preprocess(
+        bytecode,
+        io_device.memory_layout.clone(),
+        1 << 20,
+        1 << 20,
+        1 << 20,
+    );
+
+    let prove_start = Instant::now();
+    let (jolt_proof, jolt_commitments) =
+        RV32IJoltVM::prove(io_device, trace, preprocessing.clone());
+    let prover_time_ms = prove_start.elapsed().as_secs_f64() * 1000.0;
+
+    // Measure the size of the full serialized `Proof` (proof + commitments),
+    // not just the inner `RV32IJoltProof`. A saved proof file holds this
+    // payload plus the container header that `Proof::save_to_file` prepends.
+    let proof = Proof {
+        proof: jolt_proof,
+        commitments: jolt_commitments,
+    };
+    let proof_size_bytes = proof.size()?;
+
+    let verify_start = Instant::now();
+    RV32IJoltVM::verify(preprocessing, proof.proof, proof.commitments)
+        .map_err(|err| eyre!("verification failed: {err}"))?;
+    let verifier_time_ms = verify_start.elapsed().as_secs_f64() * 1000.0;
+
+    Ok(RunMetrics {
+        input: String::new(),
+        prover_time_ms,
+        verifier_time_ms,
+        proof_size_bytes,
+    })
+}
+
+fn gather_host_info() -> HostInfo {
+    let mut sys = System::new_all();
+    sys.refresh_all();
+
+    HostInfo {
+        os: System::name().unwrap_or("UNKNOWN".to_string()),
+        os_version: System::os_version().unwrap_or("UNKNOWN".to_string()),
+        host_name: System::host_name().unwrap_or("UNKNOWN".to_string()),
+        cpus: sys.cpus().len(),
+        ram_gb: sys.total_memory() as f64 / 1_000_000_000.0,
+    }
+}
+
+fn git_describe() -> String {
+    ProcessCommand::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|tag| tag.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn print_report(report: &BenchReport) {
+    for workload in &report.workloads {
+        println!("{} ({}):", workload.name, workload.function);
+        for run in &workload.runs {
+            println!(
+                "  [{}] prover: {:.2}ms, verifier: {:.2}ms, proof size: {} bytes",
+                run.input, run.prover_time_ms, run.verifier_time_ms, run.proof_size_bytes
+            );
+        }
+    }
+}
+
+fn submit_report(url: &str, report: &BenchReport) -> Result<()> {
+    let client = Client::builder().user_agent("Mozilla/5.0").build()?;
+    let response = client.post(url).json(report).send()?;
+    if !response.status().is_success() {
+        return Err(eyre!("collector responded with {}", response.status()));
+    }
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+    process::Command as ProcessCommand,
+};
+
+use eyre::{eyre, Result};
+use sha2::{Digest, Sha256};
+
+pub fn run(guest: PathBuf) {
+    if let Err(err) = build_in_container(&guest) {
+        eprintln!("container build failed: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn build_in_container(guest: &Path) -> Result<()> {
+    let guest = fs::canonicalize(guest)?;
+    let dockerfile_dir = guest.join(".jolt-build");
+    fs::create_dir_all(&dockerfile_dir)?;
+
+    let target = target_lexicon::HOST.to_string();
+    let (toolchain_version, toolchain_sha256) =
+        crate::toolchain::locked_entry(&target).ok_or_else(|| {
+            eyre!(
+                "no pinned toolchain found for {target} in jolt-toolchain.lock; \
+                 run `jolt install-toolchain` first"
+            )
+        })?;
+    let toolchain_url = crate::toolchain::toolchain_url(&toolchain_version, &target);
+
+    println!("pinning base image {RUST_BASE_IMAGE}...");
+    let base_image_digest = pin_base_image()?;
+
+    let dockerfile_contents = DOCKERFILE_TEMPLATE
+        .replace("{BASE_IMAGE}", &base_image_digest)
+        .replace("{TOOLCHAIN_URL}", &toolchain_url)
+        .replace("{TOOLCHAIN_SHA256}", &toolchain_sha256);
+
+    let dockerfile_path = dockerfile_dir.join("Dockerfile");
+    let mut dockerfile = File::create(&dockerfile_path)?;
+    dockerfile.write_all(dockerfile_contents.as_bytes())?;
+
+    let image_tag = "jolt-guest-build";
+    println!("building container image...");
+    run_docker(&[
+        "build",
+        "-t",
+        image_tag,
+        "-f",
+        dockerfile_path.to_str().ok_or_else(|| eyre!("non-utf8 path"))?,
+        dockerfile_dir
+            .to_str()
+            .ok_or_else(|| eyre!("non-utf8 path"))?,
+    ])?;
+
+    println!("compiling guest...");
+    run_docker(&[
+        "run",
+        "--rm",
+        "-v",
+        &format!(
+            "{}:/guest",
+            guest.to_str().ok_or_else(|| eyre!("non-utf8 path"))?
+        ),
+        image_tag,
+    ])?;
+
+    let elf_path = guest.join("target/riscv32i-unknown-none-elf/release/guest");
+    let elf_bytes = fs::read(&elf_path)
+        .map_err(|_| eyre!("expected ELF at {} after container build", elf_path.display()))?;
+    let digest = Sha256::digest(&elf_bytes);
+    println!("SHA-256: {:x}", digest);
+
+    Ok(())
+}
+
+fn run_docker(args: &[&str]) -> Result<()> {
+    let status = ProcessCommand::new("docker").args(args).status()?;
+    if !status.success() {
+        return Err(eyre!("docker exited with {status}"));
+    }
+    Ok(())
+}
+
+/// Floating tag of the base image to pin. Resolved to a content digest by
+/// `pin_base_image` before it's ever baked into a Dockerfile, so the
+/// generated image is never built from a moving target.
+const RUST_BASE_IMAGE: &str = "rust:1.81.0-slim-bookworm";
+
+/// Pulls `RUST_BASE_IMAGE` and resolves it to its content digest, so the
+/// Dockerfile we generate pins `FROM` by digest instead of a floating tag.
+fn pin_base_image() -> Result<String> {
+    run_docker(&["pull", "-q", RUST_BASE_IMAGE])?;
+
+    let output = ProcessCommand::new("docker")
+        .args([
+            "inspect",
+            "--format={{index .RepoDigests 0}}",
+            RUST_BASE_IMAGE,
+        ])
+        .output()?;
+    if !output.status.success() {
+        return Err(eyre!(
+            "failed to resolve a content digest for {RUST_BASE_IMAGE}"
+        ));
+    }
+
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
+
+const DOCKERFILE_TEMPLATE: &str = r#"FROM {BASE_IMAGE}
+
+RUN curl -fsSL {TOOLCHAIN_URL} -o /tmp/rust-toolchain.tar.gz \
+    && echo "{TOOLCHAIN_SHA256}  /tmp/rust-toolchain.tar.gz" | sha256sum -c - \
+    && mkdir -p /opt/jolt \
+    && tar -xzf /tmp/rust-toolchain.tar.gz -C /opt/jolt \
+    && rm /tmp/rust-toolchain.tar.gz
+
+RUN rustup toolchain link riscv32i-jolt-zkvm-elf /opt/jolt/rust/build/host/stage2 \
+    && rustup target add riscv32i-unknown-none-elf
+
+WORKDIR /guest
+
+COPY <<'EOF' /usr/local/bin/build-guest.sh
+#!/bin/sh
+set -eux
+RUSTFLAGS="-C codegen-units=1 -C lto=fat" \
+    cargo +riscv32i-jolt-zkvm-elf build --release --target riscv32i-unknown-none-elf
+EOF
+
+RUN chmod +x /usr/local/bin/build-guest.sh
+CMD ["/usr/local/bin/build-guest.sh"]
+"#;
@@ -0,0 +1,18 @@
+use std::path::PathBuf;
+
+use jolt_sdk::Proof;
+
+pub fn run(proof: PathBuf) {
+    match Proof::read_metadata(proof) {
+        Ok(metadata) => {
+            println!("elf hash:       {}", metadata.elf_hash);
+            println!("params:         {}", metadata.params);
+            println!("public inputs:  {} bytes", metadata.public_inputs.len());
+            println!("public outputs: {} bytes", metadata.public_outputs.len());
+        }
+        Err(err) => {
+            eprintln!("inspect failed: {err}");
+            std::process::exit(1);
+        }
+    }
+}
@@ -1,15 +1,20 @@
 use std::{
     fs::{self, File},
     io::Write,
+    path::PathBuf,
 };
 
 use clap::{Parser, Subcommand};
 use eyre::Result;
 use rand::prelude::SliceRandom;
-use reqwest::blocking::Client;
-use serde_json::Value;
 use sysinfo::System;
 
+mod bench;
+mod container;
+mod inspect;
+mod registry;
+mod toolchain;
+
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
 struct Cli {
@@ -25,14 +30,85 @@ enum Command {
         name: String,
     },
     /// Installs the required RISC-V toolchains for Rust
-    InstallToolchain,
+    InstallToolchain {
+        /// Specific toolchain release tag to install, instead of the latest
+        #[arg(long)]
+        version: Option<String>,
+        /// Re-resolve the toolchain version and rewrite jolt-toolchain.lock
+        #[arg(long)]
+        update: bool,
+    },
+    /// Runs JSON workload files through a full prove/verify cycle and reports timing and size metrics
+    Bench {
+        /// Paths to JSON workload files to run
+        workloads: Vec<PathBuf>,
+        /// URL to POST the resulting report to, for CI regression tracking
+        #[arg(long)]
+        report_url: Option<String>,
+    },
+    /// Builds the guest ELF inside a pinned container image for bit-for-bit reproducible output
+    Build {
+        /// Path to the guest crate to build
+        guest: PathBuf,
+    },
+    /// Publishes a proof to a content-addressed git store under `<name>` (e.g. `program@version`)
+    Publish {
+        /// Path to the proof file to publish
+        proof: PathBuf,
+        /// Name to publish the proof under, e.g. `fib@v1`
+        name: String,
+        /// Path to the bare git repository to publish into
+        #[arg(long, default_value = "jolt-registry.git")]
+        store: PathBuf,
+    },
+    /// Fetches a published proof by name from a remote content-addressed git store
+    Fetch {
+        /// Name the proof was published under, e.g. `fib@v1`
+        name: String,
+        /// Path to write the fetched proof to
+        out: PathBuf,
+        /// URL or path of the remote git store to fetch from
+        #[arg(long)]
+        store: String,
+        /// SHA-256 the fetched proof must hash to, obtained out of band (e.g. from `jolt publish`'s
+        /// output); without this, only the remote's own self-consistency is checked
+        #[arg(long)]
+        expect_hash: Option<String>,
+    },
+    /// Serves a content-addressed git store of proofs over the git smart-HTTP protocol
+    Serve {
+        /// Path to the bare git repository to serve
+        store: PathBuf,
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:9418")]
+        addr: String,
+    },
+    /// Prints a proof container's embedded metadata without verifying it
+    Inspect {
+        /// Path to the proof file to inspect
+        proof: PathBuf,
+    },
 }
 
 fn main() {
     let cli = Cli::parse();
     match cli.command {
         Command::New { name } => create_project(name),
-        Command::InstallToolchain => install_toolchains(),
+        Command::InstallToolchain { version, update } => toolchain::install(version, update),
+        Command::Bench {
+            workloads,
+            report_url,
+        } => bench::run(workloads, report_url),
+        Command::Build { guest } => container::run(guest),
+        Command::Publish { proof, name, store } => registry::publish(proof, name, store),
+        Command::Fetch {
+            name,
+            out,
+            store,
+            expect_hash,
+        } => registry::fetch(name, out, store, expect_hash),
+        Command::Serve { store, addr } => registry::serve(store, addr),
+        Command::Inspect { proof } => inspect::run(proof),
     }
 }
 
@@ -42,88 +118,6 @@ fn create_project(name: String) {
     create_guest_files(&name).expect("file creation failed");
 }
 
-fn install_toolchains() {
-    install_no_std_toolchain();
-    install_jolt_toolchain();
-    display_welcome();
-}
-
-fn install_no_std_toolchain() {
-    std::process::Command::new("rustup")
-        .args(["target", "add", "riscv32i-unknown-none-elf"])
-        .output()
-        .expect("could not install toolchain");
-}
-
-fn install_jolt_toolchain() {
-    let target = target_lexicon::HOST.to_string();
-    let client = Client::builder().user_agent("Mozilla/5.0").build().unwrap();
-    let url = get_jolt_toolchain_url(&client, &target);
-    println!("downloading toolchain...");
-    download_jolt_toolchain(&client, &url);
-    unpack_toolchain();
-    link_toolchain();
-}
-
-fn link_toolchain() {
-    let output = std::process::Command::new("rustup")
-        .args([
-            "toolchain",
-            "link",
-            "riscv32i-jolt-zkvm-elf",
-            dirs::home_dir()
-                .unwrap()
-                .join(".jolt/rust/build/host/stage2")
-                .to_str()
-                .unwrap(),
-        ])
-        .output()
-        .expect("failed to link toolchain");
-
-    if !output.status.success() {
-        println!("{}", String::from_utf8(output.stderr).unwrap());
-    }
-}
-
-fn unpack_toolchain() {
-    let output = std::process::Command::new("tar")
-        .args(["-xzf", "rust-toolchain.tar.gz"])
-        .current_dir(dirs::home_dir().unwrap().join(".jolt"))
-        .output()
-        .expect("unpacking toolchain failed");
-
-    if !output.status.success() {
-        println!("{}", String::from_utf8(output.stderr).unwrap());
-    }
-}
-
-fn download_jolt_toolchain(client: &Client, url: &str) {
-    let bytes = client.get(url).send().unwrap().bytes().unwrap();
-    let jolt_dir = dirs::home_dir().unwrap().join(".jolt");
-    if !jolt_dir.exists() {
-        fs::create_dir(&jolt_dir).unwrap();
-    }
-
-    let path = jolt_dir.join("rust-toolchain.tar.gz");
-    fs::write(path, &bytes).unwrap();
-}
-
-fn get_jolt_toolchain_url(client: &Client, target: &str) -> String {
-    let json = client
-        .get("https://api.github.com/repos/a16z/rust/releases/latest")
-        .send()
-        .unwrap()
-        .json::<Value>()
-        .unwrap();
-
-    let tag = json["tag_name"].as_str().unwrap();
-
-    format!(
-        "https://github.com/a16z/rust/releases/download/{}/rust-toolchain-{}.tar.gz",
-        tag, target
-    )
-}
-
 fn create_folder_structure(name: &str) -> Result<()> {
     fs::create_dir(name)?;
     fs::create_dir(format!("{}/src", name))?;
@@ -160,7 +154,7 @@ fn create_guest_files(name: &str) -> Result<()> {
     Ok(())
 }
 
-fn display_welcome() {
+pub(crate) fn display_welcome() {
     display_greeting();
     println!("{}", "-".repeat(80));
     display_sysinfo();
@@ -0,0 +1,315 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    process::{Command as ProcessCommand, Stdio},
+};
+
+use eyre::{eyre, Result};
+use jolt_sdk::Proof;
+use sha2::{Digest, Sha256};
+
+/// Publishes a proof file into a content-addressed git store, recording it
+/// under `refs/proofs/<name>` so it can be fetched later by anyone who can
+/// reach `store`. Run `jolt serve <store>` (or point any git smart-HTTP
+/// server, e.g. `git http-backend`, at `store`) to let standard git clients
+/// fetch it, since the index is just the store's own ref namespace.
+pub fn publish(proof: PathBuf, name: String, store: PathBuf) {
+    if let Err(err) = publish_inner(&proof, &name, &store) {
+        eprintln!("publish failed: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn publish_inner(proof_path: &Path, name: &str, store: &Path) -> Result<()> {
+    ensure_bare_repo(store)?;
+
+    let proof_path = fs::canonicalize(proof_path)?;
+
+    // Round-trip through `Proof` so a malformed or unrelated file can't be
+    // published under a name: a file that doesn't parse as a framed proof
+    // container, or whose payload doesn't deserialize, is rejected here.
+    let metadata = Proof::read_metadata(&proof_path)?;
+    let _proof = Proof::from_file(&proof_path, &metadata.elf_hash)?;
+
+    // Hash the exact bytes we're about to store, so the recorded content
+    // address matches what `fetch` recomputes from the blob it retrieves.
+    let bytes = fs::read(&proof_path)?;
+    let content_hash = format!("{:x}", Sha256::digest(&bytes));
+
+    let git_oid = git(
+        store,
+        &[
+            "hash-object",
+            "-w",
+            proof_path.to_str().ok_or_else(|| eyre!("non-utf8 path"))?,
+        ],
+    )?;
+    let git_oid = git_oid.trim();
+
+    git(store, &["update-ref", &content_ref(&content_hash), git_oid])?;
+    git(store, &["update-ref", &proof_ref(name), git_oid])?;
+
+    println!("published {name} as {content_hash}");
+    Ok(())
+}
+
+/// Fetches a named proof from a remote content-addressed git store exposed
+/// over the git smart-HTTP protocol and writes the raw proof bytes to `out`.
+///
+/// Without `expect_hash`, the only check performed is that `remote` itself
+/// consistently agrees the blob served under `name` is the same one recorded
+/// under `refs/proofs-by-hash/<hash>` — that catches accidental corruption in
+/// transport or storage, but a malicious or compromised `remote` can satisfy
+/// it trivially, since both answers come from the same place. To actually
+/// anchor trust outside the store, pass `expect_hash` (e.g. the hash printed
+/// by `jolt publish`, obtained out of band) and it's checked instead.
+pub fn fetch(name: String, out: PathBuf, remote: String, expect_hash: Option<String>) {
+    if let Err(err) = fetch_inner(&name, &out, &remote, expect_hash.as_deref()) {
+        eprintln!("fetch failed: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn fetch_inner(name: &str, out: &Path, remote: &str, expect_hash: Option<&str>) -> Result<()> {
+    let workdir = std::env::temp_dir().join(format!("jolt-fetch-{}", std::process::id()));
+    fs::create_dir_all(&workdir)?;
+    run(&workdir, &["init", "--bare", "-q", "."])?;
+
+    let name_refspec = format!("{0}:{0}", proof_ref(name));
+    run(&workdir, &["fetch", "-q", remote, &name_refspec])?;
+
+    let oid = git(&workdir, &["rev-parse", &proof_ref(name)])?;
+    let oid = oid.trim().to_string();
+    let bytes = cat_blob(&workdir, &oid)?;
+    let content_hash = format!("{:x}", Sha256::digest(&bytes));
+
+    match expect_hash {
+        // Anchored against a hash the caller supplied out of band: this is
+        // the actual tamper-resistance guarantee, independent of `remote`.
+        Some(expected) if expected != content_hash => {
+            return Err(eyre!(
+                "expected hash {expected} but the fetched blob hashes to {content_hash}; \
+                 refusing to trust {remote}"
+            ));
+        }
+        Some(_) => {}
+        // No externally-trusted hash was given, so fall back to asking
+        // `remote` to corroborate itself. This only detects corruption in
+        // transport/storage, not a store that's lying about both refs.
+        None => {
+            let hash_refspec = format!("{0}:{0}", content_ref(&content_hash));
+            run(&workdir, &["fetch", "-q", remote, &hash_refspec]).map_err(|_| {
+                eyre!(
+                    "corruption check failed: {remote} has no refs/proofs-by-hash/{content_hash} \
+                     entry for the blob served under {name}; it may have been corrupted in transit. \
+                     Pass --expect-hash if you don't trust {remote} itself"
+                )
+            })?;
+            let content_oid = git(&workdir, &["rev-parse", &content_ref(&content_hash)])?;
+            if content_oid.trim() != oid {
+                return Err(eyre!(
+                    "corruption check failed: {name} resolves to {oid} but refs/proofs-by-hash/{content_hash} points at {}",
+                    content_oid.trim()
+                ));
+            }
+        }
+    }
+
+    let len = bytes.len();
+    fs::write(out, bytes)?;
+    fs::remove_dir_all(&workdir)?;
+
+    println!("fetched {name} ({len} bytes, content hash {content_hash}) to {}", out.display());
+    Ok(())
+}
+
+/// Serves `store` over the git smart-HTTP protocol by running each request
+/// through `git http-backend` as a CGI script, the same mechanism apache/
+/// nginx use to expose `ls-refs`/`fetch` to unmodified git clients.
+pub fn serve(store: PathBuf, addr: String) {
+    if let Err(err) = serve_inner(&store, &addr) {
+        eprintln!("serve failed: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn serve_inner(store: &Path, addr: &str) -> Result<()> {
+    ensure_bare_repo(store)?;
+    let store = fs::canonicalize(store)?;
+
+    let listener = TcpListener::bind(addr)?;
+    println!("serving {} over http://{addr}", store.display());
+    for stream in listener.incoming() {
+        let stream = stream?;
+        if let Err(err) = handle_request(stream, &store) {
+            eprintln!("request failed: {err}");
+        }
+    }
+    Ok(())
+}
+
+fn handle_request(mut stream: TcpStream, store: &Path) -> Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts
+        .next()
+        .ok_or_else(|| eyre!("malformed request line"))?
+        .to_string();
+    let target = parts
+        .next()
+        .ok_or_else(|| eyre!("malformed request line"))?
+        .to_string();
+
+    let mut content_length = 0usize;
+    let mut content_type = String::new();
+    let mut git_protocol = String::new();
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header)?;
+        if header.trim().is_empty() {
+            break;
+        }
+        if let Some((key, value)) = header.split_once(':') {
+            match key.trim().to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.trim().parse().unwrap_or(0),
+                "content-type" => content_type = value.trim().to_string(),
+                "git-protocol" => git_protocol = value.trim().to_string(),
+                _ => {}
+            }
+        }
+    }
+
+    let (path_info, query_string) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), query.to_string()),
+        None => (target.clone(), String::new()),
+    };
+
+    // `git http-backend` is the same CGI program real git servers run behind
+    // apache/nginx; it implements `info/refs`, `ls-refs` and `fetch` packfile
+    // negotiation itself, so all this does is bridge raw TCP to its CGI
+    // environment and stream its output back unmodified.
+    let mut child = ProcessCommand::new("git")
+        .arg("http-backend")
+        .env("GIT_PROJECT_ROOT", store)
+        .env("GIT_HTTP_EXPORT_ALL", "1")
+        .env("REQUEST_METHOD", &method)
+        .env("PATH_INFO", &path_info)
+        .env("QUERY_STRING", &query_string)
+        .env("CONTENT_TYPE", &content_type)
+        .env("CONTENT_LENGTH", content_length.to_string())
+        .env("GIT_PROTOCOL", &git_protocol)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+
+    if content_length > 0 {
+        let mut body = vec![0u8; content_length];
+        reader.read_exact(&mut body)?;
+        child.stdin.take().unwrap().write_all(&body)?;
+    }
+
+    let output = child.wait_with_output()?;
+    let (header_end, body_start) = output
+        .stdout
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .map(|i| (i, i + 4))
+        .or_else(|| {
+            output
+                .stdout
+                .windows(2)
+                .position(|w| w == b"\n\n")
+                .map(|i| (i, i + 2))
+        })
+        .unwrap_or((output.stdout.len(), output.stdout.len()));
+    let cgi_headers = &output.stdout[..header_end];
+    let body = &output.stdout[body_start..];
+
+    let (status, headers) = extract_cgi_status(cgi_headers);
+
+    stream.write_all(format!("HTTP/1.1 {status}\r\n").as_bytes())?;
+    stream.write_all(&headers)?;
+    stream.write_all(b"\r\n")?;
+    stream.write_all(body)?;
+    Ok(())
+}
+
+/// `git http-backend` signals failure via a CGI `Status:` pseudo-header
+/// (e.g. `Status: 404 Not Found`) rather than a normal HTTP status line.
+/// Pull it out and translate it into one, defaulting to `200 OK` when the
+/// backend didn't emit one at all.
+fn extract_cgi_status(headers: &[u8]) -> (String, Vec<u8>) {
+    let mut status = "200 OK".to_string();
+    let mut remaining = Vec::with_capacity(headers.len());
+
+    for line in headers.split(|&b| b == b'\n') {
+        let trimmed = line.strip_suffix(b"\r").unwrap_or(line);
+        if let Some(value) = trimmed.strip_prefix(b"Status:") {
+            status = String::from_utf8_lossy(value).trim().to_string();
+            continue;
+        }
+        remaining.extend_from_slice(line);
+        remaining.push(b'\n');
+    }
+
+    (status, remaining)
+}
+
+fn proof_ref(name: &str) -> String {
+    format!("refs/proofs/{name}")
+}
+
+/// Content-addressed ref: every artifact is also reachable by the SHA-256 of
+/// its own bytes, independent of whatever human-readable name(s) point to it.
+fn content_ref(content_hash: &str) -> String {
+    format!("refs/proofs-by-hash/{content_hash}")
+}
+
+fn ensure_bare_repo(store: &Path) -> Result<()> {
+    if !store.join("HEAD").exists() {
+        fs::create_dir_all(store)?;
+        run(store, &["init", "--bare", "-q", "."])?;
+    }
+    Ok(())
+}
+
+fn run(repo: &Path, args: &[&str]) -> Result<()> {
+    let output = ProcessCommand::new("git")
+        .arg("--git-dir")
+        .arg(repo)
+        .args(args)
+        .output()?;
+    if !output.status.success() {
+        return Err(eyre!(String::from_utf8_lossy(&output.stderr).into_owned()));
+    }
+    Ok(())
+}
+
+fn git(repo: &Path, args: &[&str]) -> Result<String> {
+    let output = ProcessCommand::new("git")
+        .arg("--git-dir")
+        .arg(repo)
+        .args(args)
+        .output()?;
+    if !output.status.success() {
+        return Err(eyre!(String::from_utf8_lossy(&output.stderr).into_owned()));
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+fn cat_blob(repo: &Path, hash: &str) -> Result<Vec<u8>> {
+    let output = ProcessCommand::new("git")
+        .arg("--git-dir")
+        .arg(repo)
+        .args(["cat-file", "blob", hash])
+        .output()?;
+    if !output.status.success() {
+        return Err(eyre!(String::from_utf8_lossy(&output.stderr).into_owned()));
+    }
+    Ok(output.stdout)
+}
@@ -0,0 +1,190 @@
+use std::{collections::HashMap, fs};
+
+use eyre::{eyre, Result};
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+const LOCKFILE_NAME: &str = "jolt-toolchain.lock";
+
+/// Resolved toolchain release plus the checksum of the tarball for each host
+/// target it's been installed on, so repeat installs are reproducible and
+/// tamper-evident.
+#[derive(Default, Serialize, Deserialize)]
+struct Lockfile {
+    version: String,
+    #[serde(default)]
+    checksums: HashMap<String, String>,
+}
+
+pub fn install(version: Option<String>, update: bool) {
+    if let Err(err) = install_inner(version, update) {
+        eprintln!("install-toolchain failed: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn install_inner(version: Option<String>, update: bool) -> Result<()> {
+    install_no_std_toolchain()?;
+    install_jolt_toolchain(version, update)?;
+    super::display_welcome();
+    Ok(())
+}
+
+fn install_no_std_toolchain() -> Result<()> {
+    let output = std::process::Command::new("rustup")
+        .args(["target", "add", "riscv32i-unknown-none-elf"])
+        .output()?;
+    if !output.status.success() {
+        return Err(eyre!(String::from_utf8_lossy(&output.stderr).into_owned()));
+    }
+    Ok(())
+}
+
+fn install_jolt_toolchain(version: Option<String>, update: bool) -> Result<()> {
+    let target = target_lexicon::HOST.to_string();
+    let client = Client::builder().user_agent("Mozilla/5.0").build()?;
+
+    let lockfile_path = dirs::home_dir()
+        .ok_or_else(|| eyre!("could not determine home directory"))?
+        .join(".jolt")
+        .join(LOCKFILE_NAME);
+    let mut lockfile = if update {
+        Lockfile::default()
+    } else {
+        read_lockfile(&lockfile_path).unwrap_or_default()
+    };
+
+    // An explicit `--version` always pins to that tag, even if a lockfile
+    // from a previous install is already resolved to a different one. If the
+    // requested tag differs from what's locked, its checksum hasn't been
+    // verified yet, so drop the stale entry and let it be re-recorded below.
+    match version {
+        Some(requested) => {
+            if lockfile.version != requested {
+                lockfile.checksums.clear();
+            }
+            lockfile.version = requested;
+        }
+        None if lockfile.version.is_empty() => {
+            lockfile.version = resolve_latest_version(&client)?;
+        }
+        None => {}
+    }
+
+    let url = toolchain_url(&lockfile.version, &target);
+    println!("downloading toolchain {}...", lockfile.version);
+    let bytes = download_jolt_toolchain(&client, &url)?;
+
+    let digest = format!("{:x}", Sha256::digest(&bytes));
+    match lockfile.checksums.get(&target) {
+        Some(expected) if expected != &digest => {
+            return Err(eyre!(
+                "checksum mismatch for {target}: expected {expected}, got {digest}; \
+                 run with --update to re-resolve the toolchain"
+            ));
+        }
+        Some(_) => {}
+        None => {
+            lockfile.checksums.insert(target.clone(), digest);
+        }
+    }
+
+    write_tarball(&bytes)?;
+    write_lockfile(&lockfile_path, &lockfile)?;
+    unpack_toolchain()?;
+    link_toolchain()?;
+    Ok(())
+}
+
+fn resolve_latest_version(client: &Client) -> Result<String> {
+    let json = client
+        .get("https://api.github.com/repos/a16z/rust/releases/latest")
+        .send()?
+        .json::<Value>()?;
+    json["tag_name"]
+        .as_str()
+        .map(|tag| tag.to_string())
+        .ok_or_else(|| eyre!("release response missing tag_name"))
+}
+
+pub(crate) fn toolchain_url(tag: &str, target: &str) -> String {
+    format!("https://github.com/a16z/rust/releases/download/{tag}/rust-toolchain-{target}.tar.gz")
+}
+
+/// Reads the resolved version and checksum for `target` out of
+/// `jolt-toolchain.lock`, so other subcommands (e.g. `jolt build`) can embed
+/// the exact same pinned, checksum-verified toolchain a local install used.
+pub(crate) fn locked_entry(target: &str) -> Option<(String, String)> {
+    let lockfile_path = dirs::home_dir()?.join(".jolt").join(LOCKFILE_NAME);
+    let lockfile = read_lockfile(&lockfile_path)?;
+    let checksum = lockfile.checksums.get(target)?.clone();
+    Some((lockfile.version, checksum))
+}
+
+fn read_lockfile(path: &std::path::Path) -> Option<Lockfile> {
+    let contents = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+fn write_lockfile(path: &std::path::Path, lockfile: &Lockfile) -> Result<()> {
+    let contents = serde_json::to_string_pretty(lockfile)?;
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+fn download_jolt_toolchain(client: &Client, url: &str) -> Result<Vec<u8>> {
+    let bytes = client.get(url).send()?.bytes()?.to_vec();
+    let jolt_dir = dirs::home_dir()
+        .ok_or_else(|| eyre!("could not determine home directory"))?
+        .join(".jolt");
+    if !jolt_dir.exists() {
+        fs::create_dir(&jolt_dir)?;
+    }
+    Ok(bytes)
+}
+
+fn write_tarball(bytes: &[u8]) -> Result<()> {
+    let path = dirs::home_dir()
+        .ok_or_else(|| eyre!("could not determine home directory"))?
+        .join(".jolt")
+        .join("rust-toolchain.tar.gz");
+    fs::write(path, bytes)?;
+    Ok(())
+}
+
+fn unpack_toolchain() -> Result<()> {
+    let output = std::process::Command::new("tar")
+        .args(["-xzf", "rust-toolchain.tar.gz"])
+        .current_dir(
+            dirs::home_dir()
+                .ok_or_else(|| eyre!("could not determine home directory"))?
+                .join(".jolt"),
+        )
+        .output()?;
+
+    if !output.status.success() {
+        return Err(eyre!(String::from_utf8_lossy(&output.stderr).into_owned()));
+    }
+    Ok(())
+}
+
+fn link_toolchain() -> Result<()> {
+    let stage2 = dirs::home_dir()
+        .ok_or_else(|| eyre!("could not determine home directory"))?
+        .join(".jolt/rust/build/host/stage2");
+    let output = std::process::Command::new("rustup")
+        .args([
+            "toolchain",
+            "link",
+            "riscv32i-jolt-zkvm-elf",
+            stage2.to_str().ok_or_else(|| eyre!("non-utf8 path"))?,
+        ])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(eyre!(String::from_utf8_lossy(&output.stderr).into_owned()));
+    }
+    Ok(())
+}